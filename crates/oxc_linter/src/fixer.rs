@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use oxc_diagnostics::Error;
+use oxc_span::Span;
+
+/// A single text edit keyed by a byte [`Span`] of the original source.
+///
+/// An empty [`content`](Fix::content) with a non-empty span is a deletion; an
+/// empty span (`start == end`) with non-empty content is an insertion; anything
+/// else is a replacement.
+#[derive(Debug, Clone)]
+pub struct Fix<'a> {
+    pub content: Cow<'a, str>,
+    pub span: Span,
+}
+
+impl<'a> Fix<'a> {
+    pub const fn delete(span: Span) -> Self {
+        Self { content: Cow::Borrowed(""), span }
+    }
+
+    pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
+        Self { content: content.into(), span }
+    }
+}
+
+/// Passed to a rule's fix closure to build a [`Fix`] without having to spell out
+/// spans by hand. Mirrors the helpers other JSX a11y fixers rely on.
+#[derive(Clone, Copy)]
+pub struct RuleFixer;
+
+impl RuleFixer {
+    /// Replace the text covered by `span` with `content`.
+    pub fn replace<'a, T: Into<Cow<'a, str>>>(self, span: Span, content: T) -> Fix<'a> {
+        Fix::new(content, span)
+    }
+
+    /// Insert `content` immediately after `span`.
+    pub fn insert_after<'a, T: Into<Cow<'a, str>>>(self, span: Span, content: T) -> Fix<'a> {
+        Fix::new(content, Span::new(span.end, span.end))
+    }
+
+    /// Insert `content` immediately before `span`.
+    pub fn insert_before<'a, T: Into<Cow<'a, str>>>(self, span: Span, content: T) -> Fix<'a> {
+        Fix::new(content, Span::new(span.start, span.start))
+    }
+
+    /// Delete the text covered by `span`.
+    pub fn delete<'a>(self, span: Span) -> Fix<'a> {
+        Fix::delete(span)
+    }
+}
+
+/// A diagnostic together with the optional [`Fix`] a rule attached to it.
+#[derive(Debug)]
+pub struct Message<'a> {
+    pub error: Error,
+    pub fix: Option<Fix<'a>>,
+    fixed: bool,
+}
+
+impl<'a> Message<'a> {
+    pub fn new(error: Error, fix: Option<Fix<'a>>) -> Self {
+        Self { error, fix, fixed: false }
+    }
+}
+
+/// The result of applying every non-overlapping fix to the source text.
+#[derive(Debug)]
+pub struct FixResult<'a> {
+    /// Whether any fix was applied.
+    pub fixed: bool,
+    /// The rewritten source.
+    pub fixed_code: Cow<'a, str>,
+    /// The messages that remain: those without a fix and those whose fix was
+    /// not applied. Messages whose fix was applied are dropped.
+    pub messages: Vec<Message<'a>>,
+}
+
+/// Upper bound on fix/re-lint iterations, so a pair of rules that keep undoing
+/// each other cannot spin forever.
+const MAX_FIX_ITERATIONS: usize = 10;
+
+/// Applies the fixes collected during a lint pass to the source text.
+///
+/// [`fix`](Fixer::fix) is a single conflict-resolution sweep: fixes are applied
+/// left to right and whenever two overlap only the first one wins, the rest
+/// being returned unapplied in [`FixResult::messages`]. Overlapping autofixes
+/// converge across sweeps via [`fix_until_stable`](Fixer::fix_until_stable),
+/// which re-lints the rewritten source and re-applies until it stops changing.
+pub struct Fixer<'a> {
+    source_text: &'a str,
+    messages: Vec<Message<'a>>,
+}
+
+impl<'a> Fixer<'a> {
+    pub fn new(source_text: &'a str, messages: Vec<Message<'a>>) -> Self {
+        Self { source_text, messages }
+    }
+
+    pub fn fix(mut self) -> FixResult<'a> {
+        let source_text = self.source_text;
+        let fixes: Vec<&Fix<'a>> =
+            self.messages.iter().filter_map(|m| m.fix.as_ref()).collect();
+        if fixes.is_empty() {
+            return FixResult {
+                fixed: false,
+                fixed_code: Cow::Borrowed(source_text),
+                messages: self.messages,
+            };
+        }
+
+        let (output, applied, fixed) = apply_sweep(source_text, &fixes);
+
+        // `applied` is indexed over the fixable messages in order; walk both in
+        // lockstep to mark the ones whose edit made it into this sweep.
+        let mut applied = applied.into_iter();
+        for message in &mut self.messages {
+            if message.fix.is_some() && applied.next() == Some(true) {
+                message.fixed = true;
+            }
+        }
+
+        let remaining = self.messages.into_iter().filter(|m| !m.fixed).collect();
+        FixResult { fixed, fixed_code: Cow::Owned(output), messages: remaining }
+    }
+
+    /// Re-lint-until-stable driver: apply a sweep, hand the rewritten source to
+    /// `relint` to recompute fixes, and repeat until nothing changes (or
+    /// [`MAX_FIX_ITERATIONS`] is hit). This is what lets an edit dropped for
+    /// overlapping another in one pass be re-applied once the intervening edit
+    /// has landed. Returns the final source and whether any fix was applied.
+    pub fn fix_until_stable(
+        source_text: &str,
+        mut relint: impl FnMut(&str) -> Vec<Fix<'_>>,
+    ) -> (String, bool) {
+        let mut current = source_text.to_string();
+        let mut fixed = false;
+        for _ in 0..MAX_FIX_ITERATIONS {
+            let fixes = relint(&current);
+            let refs: Vec<&Fix> = fixes.iter().collect();
+            if refs.is_empty() {
+                break;
+            }
+            let (output, _, any) = apply_sweep(&current, &refs);
+            if !any || output == current {
+                break;
+            }
+            current = output;
+            fixed = true;
+        }
+        (current, fixed)
+    }
+}
+
+/// Apply one non-overlapping left-to-right sweep of `fixes` to `source_text`.
+///
+/// Returns the rewritten source, a per-fix flag (in the input order) recording
+/// which edits were applied, and whether any edit landed at all.
+fn apply_sweep(source_text: &str, fixes: &[&Fix]) -> (String, Vec<bool>, bool) {
+    let mut order: Vec<usize> = (0..fixes.len()).collect();
+    order.sort_by_key(|&i| fixes[i].span.start);
+
+    let mut applied = vec![false; fixes.len()];
+    let mut output = String::with_capacity(source_text.len());
+    let mut last_pos: u32 = 0;
+    let mut any = false;
+    for &i in &order {
+        let fix = fixes[i];
+        let start = fix.span.start;
+        let end = fix.span.end;
+        // Skip edits that overlap one already applied this sweep.
+        if start < last_pos || start > end {
+            continue;
+        }
+        output.push_str(&source_text[last_pos as usize..start as usize]);
+        output.push_str(&fix.content);
+        last_pos = end;
+        applied[i] = true;
+        any = true;
+    }
+    output.push_str(&source_text[last_pos as usize..]);
+    (output, applied, any)
+}
@@ -0,0 +1,288 @@
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{eval_jsx_attribute_value, StaticValue},
+    AstNode,
+};
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use phf::{phf_map, phf_set};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(aria-proptypes): The value of the `{attr}` attribute must be a {expected}.")]
+#[diagnostic(severity(warning), help("`{value}` is not a valid value for `{attr}`."))]
+struct AriaProptypesDiagnostic {
+    #[label]
+    pub span: Span,
+    pub attr: String,
+    pub value: String,
+    pub expected: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaProptypes;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that the value of each `aria-*` attribute matches the value type
+    /// the ARIA specification defines for it.
+    ///
+    /// ### Why is this bad?
+    /// `aria-checked="yes"` or `aria-level="high"` are silently ignored by
+    /// assistive technology; only the spec-defined value types carry meaning.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div aria-checked="notchecked" />
+    /// <span aria-level="tall" />
+    ///
+    /// // Good
+    /// <div aria-checked="true" />
+    /// <span aria-level="3" />
+    /// ```
+    AriaProptypes,
+    correctness
+);
+
+#[derive(Clone, Copy)]
+enum AriaPropertyType {
+    Boolean,
+    Tristate,
+    Integer,
+    Number,
+    Token,
+    Tokenlist,
+    Id,
+    Idlist,
+    String,
+}
+
+static ARIA_PROPERTY_TYPES: phf::Map<&'static str, AriaPropertyType> = phf_map! {
+    "aria-busy" => AriaPropertyType::Boolean,
+    "aria-disabled" => AriaPropertyType::Boolean,
+    "aria-hidden" => AriaPropertyType::Boolean,
+    "aria-modal" => AriaPropertyType::Boolean,
+    "aria-multiline" => AriaPropertyType::Boolean,
+    "aria-multiselectable" => AriaPropertyType::Boolean,
+    "aria-readonly" => AriaPropertyType::Boolean,
+    "aria-required" => AriaPropertyType::Boolean,
+    "aria-atomic" => AriaPropertyType::Boolean,
+    "aria-checked" => AriaPropertyType::Tristate,
+    "aria-pressed" => AriaPropertyType::Tristate,
+    "aria-expanded" => AriaPropertyType::Boolean,
+    "aria-selected" => AriaPropertyType::Boolean,
+    "aria-grabbed" => AriaPropertyType::Boolean,
+    "aria-level" => AriaPropertyType::Integer,
+    "aria-colcount" => AriaPropertyType::Integer,
+    "aria-colindex" => AriaPropertyType::Integer,
+    "aria-colspan" => AriaPropertyType::Integer,
+    "aria-rowcount" => AriaPropertyType::Integer,
+    "aria-rowindex" => AriaPropertyType::Integer,
+    "aria-rowspan" => AriaPropertyType::Integer,
+    "aria-posinset" => AriaPropertyType::Integer,
+    "aria-setsize" => AriaPropertyType::Integer,
+    "aria-valuemax" => AriaPropertyType::Number,
+    "aria-valuemin" => AriaPropertyType::Number,
+    "aria-valuenow" => AriaPropertyType::Number,
+    "aria-autocomplete" => AriaPropertyType::Token,
+    "aria-current" => AriaPropertyType::Token,
+    "aria-haspopup" => AriaPropertyType::Token,
+    "aria-invalid" => AriaPropertyType::Token,
+    "aria-live" => AriaPropertyType::Token,
+    "aria-orientation" => AriaPropertyType::Token,
+    "aria-sort" => AriaPropertyType::Token,
+    "aria-dropeffect" => AriaPropertyType::Tokenlist,
+    "aria-relevant" => AriaPropertyType::Tokenlist,
+    "aria-activedescendant" => AriaPropertyType::Id,
+    "aria-controls" => AriaPropertyType::Idlist,
+    "aria-describedby" => AriaPropertyType::Idlist,
+    "aria-details" => AriaPropertyType::Id,
+    "aria-errormessage" => AriaPropertyType::Id,
+    "aria-flowto" => AriaPropertyType::Idlist,
+    "aria-labelledby" => AriaPropertyType::Idlist,
+    "aria-owns" => AriaPropertyType::Idlist,
+    "aria-keyshortcuts" => AriaPropertyType::String,
+    "aria-label" => AriaPropertyType::String,
+    "aria-placeholder" => AriaPropertyType::String,
+    "aria-roledescription" => AriaPropertyType::String,
+    "aria-valuetext" => AriaPropertyType::String,
+};
+
+/// The closed set of allowed tokens for `token`/`tokenlist` attributes.
+fn allowed_tokens(attr: &str) -> &'static phf::Set<&'static str> {
+    static AUTOCOMPLETE: phf::Set<&'static str> = phf_set! {"inline", "list", "both", "none"};
+    static CURRENT: phf::Set<&'static str> =
+        phf_set! {"page", "step", "location", "date", "time", "true", "false"};
+    static HASPOPUP: phf::Set<&'static str> =
+        phf_set! {"true", "false", "menu", "listbox", "tree", "grid", "dialog"};
+    static INVALID: phf::Set<&'static str> = phf_set! {"grammar", "false", "spelling", "true"};
+    static LIVE: phf::Set<&'static str> = phf_set! {"assertive", "off", "polite"};
+    static ORIENTATION: phf::Set<&'static str> = phf_set! {"horizontal", "vertical", "undefined"};
+    static SORT: phf::Set<&'static str> = phf_set! {"ascending", "descending", "none", "other"};
+    static DROPEFFECT: phf::Set<&'static str> =
+        phf_set! {"copy", "execute", "link", "move", "none", "popup"};
+    static RELEVANT: phf::Set<&'static str> =
+        phf_set! {"additions", "all", "removals", "text"};
+    static EMPTY: phf::Set<&'static str> = phf_set! {};
+    match attr {
+        "aria-autocomplete" => &AUTOCOMPLETE,
+        "aria-current" => &CURRENT,
+        "aria-haspopup" => &HASPOPUP,
+        "aria-invalid" => &INVALID,
+        "aria-live" => &LIVE,
+        "aria-orientation" => &ORIENTATION,
+        "aria-sort" => &SORT,
+        "aria-dropeffect" => &DROPEFFECT,
+        "aria-relevant" => &RELEVANT,
+        _ => &EMPTY,
+    }
+}
+
+impl Rule for AriaProptypes {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        for attr in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attribute) = attr else { continue };
+            let JSXAttributeName::Identifier(name) = &attribute.name else { continue };
+            let attr_name = name.name.as_str();
+            let Some(property_type) = ARIA_PROPERTY_TYPES.get(attr_name) else { continue };
+
+            let value = eval_jsx_attribute_value(attr);
+            if matches!(value, StaticValue::Unknown) {
+                continue;
+            }
+
+            if is_valid(*property_type, attr_name, &value) {
+                continue;
+            }
+
+            ctx.diagnostic(AriaProptypesDiagnostic {
+                span: attribute.span,
+                attr: attr_name.to_string(),
+                value: display_value(&value),
+                expected: expected_description(*property_type, attr_name),
+            });
+        }
+    }
+}
+
+fn is_valid(property_type: AriaPropertyType, attr: &str, value: &StaticValue) -> bool {
+    match property_type {
+        AriaPropertyType::Boolean => is_boolean(value),
+        AriaPropertyType::Tristate => is_boolean(value) || as_token(value) == Some("mixed"),
+        AriaPropertyType::Integer => as_number(value).is_some_and(|n| n.fract() == 0.0),
+        AriaPropertyType::Number => as_number(value).is_some(),
+        AriaPropertyType::Token => {
+            as_token(value).is_some_and(|t| allowed_tokens(attr).contains(t))
+        }
+        AriaPropertyType::Tokenlist => as_token(value).is_some_and(|tokens| {
+            let set = allowed_tokens(attr);
+            tokens.split_whitespace().all(|t| set.contains(t))
+        }),
+        AriaPropertyType::Id => as_token(value).is_some_and(|s| !s.trim().is_empty()),
+        AriaPropertyType::Idlist => as_token(value).is_some_and(|s| !s.trim().is_empty()),
+        AriaPropertyType::String => matches!(value, StaticValue::String(_)),
+    }
+}
+
+fn is_boolean(value: &StaticValue) -> bool {
+    match value {
+        StaticValue::Boolean(_) => true,
+        StaticValue::String(s) => matches!(s.as_ref(), "true" | "false"),
+        _ => false,
+    }
+}
+
+/// The value coerced to a string token, for `token`/`id` comparisons.
+fn as_token<'a>(value: &'a StaticValue) -> Option<&'a str> {
+    match value {
+        StaticValue::String(s) => Some(s.as_ref()),
+        StaticValue::Boolean(b) => Some(if *b { "true" } else { "false" }),
+        _ => None,
+    }
+}
+
+/// A finite number parsed from a numeric or numeric-string value.
+fn as_number(value: &StaticValue) -> Option<f64> {
+    let n = match value {
+        StaticValue::Number(n) => *n,
+        StaticValue::String(s) => s.trim().parse::<f64>().ok()?,
+        _ => return None,
+    };
+    n.is_finite().then_some(n)
+}
+
+fn display_value(value: &StaticValue) -> String {
+    match value {
+        StaticValue::String(s) => s.to_string(),
+        StaticValue::Boolean(b) => b.to_string(),
+        StaticValue::Number(n) => n.to_string(),
+        StaticValue::Null => "null".to_string(),
+        StaticValue::Undefined => "undefined".to_string(),
+        StaticValue::Unknown => String::new(),
+    }
+}
+
+fn expected_description(property_type: AriaPropertyType, attr: &str) -> String {
+    match property_type {
+        AriaPropertyType::Boolean => "boolean".to_string(),
+        AriaPropertyType::Tristate => "boolean or the string \"mixed\"".to_string(),
+        AriaPropertyType::Integer => "integer".to_string(),
+        AriaPropertyType::Number => "number".to_string(),
+        AriaPropertyType::Token | AriaPropertyType::Tokenlist => {
+            let mut tokens = allowed_tokens(attr).iter().copied().collect::<Vec<_>>();
+            tokens.sort_unstable();
+            let shape = if matches!(property_type, AriaPropertyType::Token) {
+                "single token"
+            } else {
+                "space-separated list of tokens"
+            };
+            format!("{shape} from [{}]", tokens.join(", "))
+        }
+        AriaPropertyType::Id => "string identifier".to_string(),
+        AriaPropertyType::Idlist => "space-separated list of identifiers".to_string(),
+        AriaPropertyType::String => "string".to_string(),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<div aria-busy='true' />",
+        "<div aria-busy />",
+        "<div aria-checked='mixed' />",
+        "<div aria-checked='true' />",
+        "<div aria-level='3' />",
+        "<div aria-valuenow='1.5' />",
+        "<div aria-orientation='horizontal' />",
+        "<div aria-relevant='additions text' />",
+        "<div aria-labelledby='foo bar' />",
+        "<div aria-label='close' />",
+        "<div aria-checked={foo} />",
+    ];
+
+    let fail = vec![
+        "<div aria-busy='maybe' />",
+        "<div aria-checked='notchecked' />",
+        "<div aria-level='three' />",
+        "<div aria-level='1.5' />",
+        "<div aria-valuenow='high' />",
+        "<div aria-orientation='sideways' />",
+        "<div aria-relevant='foo text' />",
+        "<div aria-activedescendant='' />",
+    ];
+
+    Tester::new_without_config(AriaProptypes::NAME, pass, fail).test_and_snapshot();
+}
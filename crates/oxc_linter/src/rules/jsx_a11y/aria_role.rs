@@ -0,0 +1,188 @@
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{eval_expression, eval_jsx_attribute_value, has_jsx_prop_lowercase, StaticValue},
+    AstNode,
+};
+use oxc_ast::{
+    ast::{Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use phf::{phf_set, Set};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(aria-role): Elements with ARIA roles must use a valid, non-abstract ARIA role.")]
+#[diagnostic(severity(warning), help("`{role}` is not a valid ARIA role."))]
+struct InvalidRoleDiagnostic {
+    #[label]
+    pub span: Span,
+    pub role: String,
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(aria-role): Abstract roles are for use by browsers and must not be used in markup.")]
+#[diagnostic(severity(warning), help("`{role}` is an abstract role and cannot be used."))]
+struct AbstractRoleDiagnostic {
+    #[label]
+    pub span: Span,
+    pub role: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaRole {
+    ignore_non_dom: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that every `role` value is a valid, concrete (non-abstract)
+    /// WAI-ARIA or DPUB-ARIA role.
+    ///
+    /// ### Why is this bad?
+    /// Invalid roles are ignored by assistive technology, and abstract roles
+    /// exist only for the ontology — browsers never expose them, so using one
+    /// in markup is always a mistake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div role="datepicker" />
+    /// <div role="range" />
+    ///
+    /// // Good
+    /// <div role="button" />
+    /// ```
+    AriaRole,
+    correctness
+);
+
+static VALID_ROLES: Set<&'static str> = phf_set! {
+    "alert", "alertdialog", "application", "article", "banner", "blockquote",
+    "button", "caption", "cell", "checkbox", "code", "columnheader", "combobox",
+    "complementary", "contentinfo", "definition", "deletion", "dialog",
+    "directory", "document", "emphasis", "feed", "figure", "form", "generic",
+    "grid", "gridcell", "group", "heading", "img", "insertion", "link", "list",
+    "listbox", "listitem", "log", "main", "marquee", "math", "menu", "menubar",
+    "menuitem", "menuitemcheckbox", "menuitemradio", "meter", "navigation",
+    "none", "note", "option", "paragraph", "presentation", "progressbar",
+    "radio", "radiogroup", "region", "row", "rowgroup", "rowheader", "scrollbar",
+    "search", "searchbox", "separator", "slider", "spinbutton", "status",
+    "strong", "subscript", "superscript", "switch", "tab", "table", "tablist",
+    "tabpanel", "term", "textbox", "time", "timer", "toolbar", "tooltip", "tree",
+    "treegrid", "treeitem",
+    // DPUB-ARIA roles.
+    "doc-abstract", "doc-acknowledgments", "doc-afterword", "doc-appendix",
+    "doc-backlink", "doc-biblioentry", "doc-bibliography", "doc-biblioref",
+    "doc-chapter", "doc-colophon", "doc-conclusion", "doc-cover", "doc-credit",
+    "doc-credits", "doc-dedication", "doc-endnote", "doc-endnotes",
+    "doc-epigraph", "doc-epilogue", "doc-errata", "doc-example", "doc-footnote",
+    "doc-foreword", "doc-glossary", "doc-glossref", "doc-index", "doc-introduction",
+    "doc-noteref", "doc-notice", "doc-pagebreak", "doc-pagelist", "doc-part",
+    "doc-preface", "doc-prologue", "doc-pullquote", "doc-qna", "doc-subtitle",
+    "doc-tip", "doc-toc",
+};
+
+static ABSTRACT_ROLES: Set<&'static str> = phf_set! {
+    "command", "composite", "input", "landmark", "range", "roletype", "section",
+    "sectionhead", "select", "structure", "widget", "window",
+};
+
+impl Rule for AriaRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_non_dom = value
+            .get(0)
+            .and_then(|v| v.get("ignoreNonDOM"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { ignore_non_dom }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let JSXElementName::Identifier(name) = &jsx_el.name else { return };
+
+        // Resolve custom components through the shared `jsx-a11y.components`
+        // mapping, then optionally skip anything that is still not a DOM element.
+        let element = ctx
+            .settings()
+            .jsx_a11y
+            .components
+            .get(name.name.as_str())
+            .map_or_else(|| name.name.as_str(), String::as_str);
+        if self.ignore_non_dom && element.chars().next().is_some_and(char::is_uppercase) {
+            return;
+        }
+
+        let Some(role_prop) = has_jsx_prop_lowercase(jsx_el, "role") else { return };
+        let JSXAttributeItem::Attribute(attr) = role_prop else { return };
+
+        for value in static_role_values(role_prop) {
+            let Some(value) = value.as_str() else { continue };
+            for role in value.split_whitespace() {
+                if ABSTRACT_ROLES.contains(role) {
+                    ctx.diagnostic(AbstractRoleDiagnostic { span: attr.span, role: role.into() });
+                } else if !VALID_ROLES.contains(role) {
+                    ctx.diagnostic(InvalidRoleDiagnostic { span: attr.span, role: role.into() });
+                }
+            }
+        }
+    }
+}
+
+/// The statically-known candidate role values, expanding a conditional into
+/// both of its branches so `role={cond ? 'button' : 'link'}` is checked twice.
+fn static_role_values<'a>(attr: &JSXAttributeItem<'a>) -> Vec<StaticValue<'a>> {
+    if let JSXAttributeItem::Attribute(a) = attr {
+        if let Some(JSXAttributeValue::ExpressionContainer(container)) = &a.value {
+            if let JSXExpression::Expression(Expression::ConditionalExpression(cond)) =
+                &container.expression
+            {
+                return vec![eval_expression(&cond.consequent), eval_expression(&cond.alternate)];
+            }
+        }
+    }
+    vec![eval_jsx_attribute_value(attr)]
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    fn settings() -> serde_json::Value {
+        serde_json::json!({
+            "jsx-a11y": {
+                "components": {
+                    "MyComponent": "div",
+                }
+            }
+        })
+    }
+
+    let ignore_non_dom = Some(serde_json::json!([{ "ignoreNonDOM": true }]));
+
+    let pass = vec![
+        ("<div role='button' />", None, None, None),
+        ("<div role='tabpanel row' />", None, None, None),
+        ("<div role='doc-abstract' />", None, None, None),
+        ("<div role={role} />", None, None, None),
+        ("<div role={cond ? 'button' : 'link'} />", None, None, None),
+        ("<Foo role='datepicker' />", ignore_non_dom.clone(), None, None),
+        ("<MyComponent role='button' />", None, Some(settings()), None),
+    ];
+
+    let fail = vec![
+        ("<div role='datepicker' />", None, None, None),
+        ("<div role='range' />", None, None, None),
+        ("<div role='button foobar' />", None, None, None),
+        ("<div role={cond ? 'button' : 'rnge'} />", None, None, None),
+        ("<MyComponent role='range' />", None, Some(settings()), None),
+    ];
+
+    Tester::new_with_settings(AriaRole::NAME, pass, fail).test_and_snapshot();
+}
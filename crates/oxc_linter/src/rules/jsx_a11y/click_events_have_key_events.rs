@@ -0,0 +1,151 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        element_interactivity, eval_jsx_attribute_value, has_explicit_role,
+        has_jsx_prop_lowercase, Interactivity, StaticValue,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(click-events-have-key-events): Visible, non-interactive elements with click handlers must have a keyboard handler.")]
+#[diagnostic(severity(warning), help("Add `onKeyDown`, `onKeyUp` or `onKeyPress` alongside `onClick`."))]
+struct ClickEventsHaveKeyEventsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct ClickEventsHaveKeyEvents;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that an element with an `onClick` handler also handles at least
+    /// one keyboard event.
+    ///
+    /// ### Why is this bad?
+    /// Click handlers that are not mirrored by keyboard handlers leave the
+    /// interaction unreachable for keyboard-only users.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div onClick={onClick} />
+    ///
+    /// // Good
+    /// <div onClick={onClick} onKeyDown={onKeyDown} />
+    /// ```
+    ClickEventsHaveKeyEvents,
+    correctness
+);
+
+const KEY_HANDLERS: [&str; 3] = ["onKeyDown", "onKeyUp", "onKeyPress"];
+
+impl Rule for ClickEventsHaveKeyEvents {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        let JSXElementName::Identifier(name) = &jsx_el.name else { return };
+
+        // Resolve custom components through the shared `jsx-a11y.components`
+        // mapping, then only consider lowercase intrinsic DOM elements.
+        let element_name = ctx
+            .settings()
+            .jsx_a11y
+            .components
+            .get(name.name.as_str())
+            .map_or_else(|| name.name.as_str(), String::as_str);
+        if !element_name.chars().next().is_some_and(char::is_lowercase) {
+            return;
+        }
+
+        let mut has_spread = false;
+        let mut attributes = FxHashMap::default();
+        for attr in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attribute) = attr else {
+                has_spread = true;
+                continue;
+            };
+            if let JSXAttributeName::Identifier(ident) = &attribute.name {
+                attributes.insert(ident.name.as_str(), ident.span);
+            }
+        }
+        if has_spread {
+            return;
+        }
+
+        let Some(click_span) = attributes.get("onClick") else { return };
+
+        // Presentation roles, hidden elements, and anything whose explicit role
+        // is non-interactive are exempt.
+        if is_presentation_role(jsx_el) || is_aria_hidden(jsx_el) {
+            return;
+        }
+        let interactivity = element_interactivity(jsx_el, element_name);
+        // Native interactive elements (`<button>`, `<a href>`, `<input>`…)
+        // handle Enter/Space themselves, and elements carrying an explicit
+        // non-interactive role are out of scope for this rule.
+        if interactivity == Interactivity::Interactive {
+            return;
+        }
+        if has_explicit_role(jsx_el) && interactivity == Interactivity::NonInteractive {
+            return;
+        }
+
+        if KEY_HANDLERS.iter().any(|handler| attributes.contains_key(handler)) {
+            return;
+        }
+
+        ctx.diagnostic(ClickEventsHaveKeyEventsDiagnostic(*click_span));
+    }
+}
+
+fn is_presentation_role(jsx_el: &oxc_ast::ast::JSXOpeningElement) -> bool {
+    has_jsx_prop_lowercase(jsx_el, "role")
+        .and_then(|role_prop| {
+            eval_jsx_attribute_value(role_prop)
+                .as_str()
+                .map(|role| matches!(role, "presentation" | "none"))
+        })
+        .unwrap_or(false)
+}
+
+fn is_aria_hidden(jsx_el: &oxc_ast::ast::JSXOpeningElement) -> bool {
+    has_jsx_prop_lowercase(jsx_el, "aria-hidden").is_some_and(|prop| {
+        matches!(eval_jsx_attribute_value(prop), StaticValue::Boolean(true))
+            || eval_jsx_attribute_value(prop).as_str() == Some("true")
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div onClick={() => void 0} onKeyDown={() => void 0} />", None, None, None),
+        ("<div onClick={() => void 0} role='presentation' />", None, None, None),
+        ("<div onClick={() => void 0} role='article' />", None, None, None),
+        ("<div onClick={() => void 0} aria-hidden='true' />", None, None, None),
+        ("<button onClick={() => void 0} />", None, None, None),
+        ("<div onClick={() => void 0} {...props} />", None, None, None),
+        ("<div />", None, None, None),
+        ("<Foo onClick={() => void 0} />", None, None, None),
+    ];
+
+    let fail = vec![
+        ("<div onClick={() => void 0} />", None, None, None),
+        ("<span onClick={() => void 0} />", None, None, None),
+    ];
+
+    Tester::new_with_settings(ClickEventsHaveKeyEvents::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,148 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(mouse-events-have-key-events): `{mouse_event}` must be accompanied by a keyboard handler.")]
+#[diagnostic(severity(warning), help("Add one of `{key_events}` for keyboard users."))]
+struct MouseEventsHaveKeyEventsDiagnostic {
+    #[label]
+    pub span: Span,
+    pub mouse_event: String,
+    pub key_events: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MouseEventsHaveKeyEvents {
+    /// Each mouse handler mapped to the keyboard handlers, any one of which
+    /// satisfies it. Defaults to `onMouseOver -> [onFocus]` and
+    /// `onMouseOut -> [onBlur]`.
+    handler_pairs: Vec<(String, Vec<String>)>,
+}
+
+impl Default for MouseEventsHaveKeyEvents {
+    fn default() -> Self {
+        Self {
+            handler_pairs: vec![
+                ("onMouseOver".to_string(), vec!["onFocus".to_string()]),
+                ("onMouseOut".to_string(), vec!["onBlur".to_string()]),
+            ],
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that a mouse event handler is paired with its keyboard
+    /// equivalent, e.g. `onMouseOver` with `onFocus`.
+    ///
+    /// ### Why is this bad?
+    /// Interactions that are only reachable with a pointer exclude keyboard and
+    /// screen-reader users.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div onMouseOver={onHover} />
+    ///
+    /// // Good
+    /// <div onMouseOver={onHover} onFocus={onHover} />
+    /// ```
+    MouseEventsHaveKeyEvents,
+    correctness
+);
+
+impl Rule for MouseEventsHaveKeyEvents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0).and_then(serde_json::Value::as_object) else {
+            return Self::default();
+        };
+        let handler_pairs = config
+            .iter()
+            .filter_map(|(mouse_event, key_events)| {
+                // A malformed entry (value not an array) would otherwise yield an
+                // empty key list that makes the mouse handler report
+                // unconditionally, so skip it and keep the remaining pairs.
+                let key_events = key_events
+                    .as_array()?
+                    .iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect();
+                Some((mouse_event.clone(), key_events))
+            })
+            .collect::<Vec<_>>();
+        if handler_pairs.is_empty() {
+            Self::default()
+        } else {
+            Self { handler_pairs }
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        let JSXElementName::Identifier(name) = &jsx_el.name else { return };
+        if !name.name.chars().next().is_some_and(char::is_lowercase) {
+            return;
+        }
+
+        // A spread could supply any handler, so we cannot prove one is missing.
+        let mut has_spread = false;
+        let mut attributes = FxHashMap::default();
+        for attr in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attribute) = attr else {
+                has_spread = true;
+                continue;
+            };
+            if let JSXAttributeName::Identifier(ident) = &attribute.name {
+                attributes.insert(ident.name.as_str(), ident.span);
+            }
+        }
+        if has_spread {
+            return;
+        }
+
+        for (mouse_event, key_events) in &self.handler_pairs {
+            let Some(span) = attributes.get(mouse_event.as_str()) else { continue };
+            if key_events.iter().any(|key_event| attributes.contains_key(key_event.as_str())) {
+                continue;
+            }
+            ctx.diagnostic(MouseEventsHaveKeyEventsDiagnostic {
+                span: *span,
+                mouse_event: mouse_event.clone(),
+                key_events: key_events.join("`, `"),
+            });
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div onMouseOver={() => void 0} onFocus={() => void 0} />", None, None, None),
+        ("<div onMouseOut={() => void 0} onBlur={() => void 0} />", None, None, None),
+        ("<div onMouseOver={() => void 0} {...props} />", None, None, None),
+        ("<div />", None, None, None),
+        ("<Foo onMouseOver={() => void 0} />", None, None, None),
+    ];
+
+    let fail = vec![
+        ("<div onMouseOver={() => void 0} />", None, None, None),
+        ("<div onMouseOut={() => void 0} />", None, None, None),
+        ("<div onMouseOver={() => void 0} onMouseOut={() => void 0} />", None, None, None),
+    ];
+
+    Tester::new_with_settings(MouseEventsHaveKeyEvents::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,130 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{element_interactivity, has_explicit_role, Interactivity},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-static-element-interactions): Non-interactive elements with interaction handlers must have a role.")]
+#[diagnostic(severity(warning), help("Add a `role` attribute describing the element's interactive purpose."))]
+struct NoStaticElementInteractionsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStaticElementInteractions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that a non-interactive intrinsic element carrying an interaction
+    /// handler also declares an ARIA `role`.
+    ///
+    /// ### Why is this bad?
+    /// A bare `<div onClick>` conveys no semantics to assistive technology; a
+    /// role tells it how the element behaves.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div onClick={onClick} />
+    ///
+    /// // Good
+    /// <div onClick={onClick} role="button" />
+    /// ```
+    NoStaticElementInteractions,
+    correctness
+);
+
+const INTERACTION_HANDLERS: [&str; 6] =
+    ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"];
+
+impl Rule for NoStaticElementInteractions {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        let JSXElementName::Identifier(name) = &jsx_el.name else { return };
+
+        // Resolve custom components through the shared `jsx-a11y.components`
+        // mapping before classifying, so `<MyComponent>` mapped to `div` is
+        // treated like the intrinsic element it stands in for.
+        let element_name = ctx
+            .settings()
+            .jsx_a11y
+            .components
+            .get(name.name.as_str())
+            .map_or_else(|| name.name.as_str(), String::as_str);
+        if element_interactivity(jsx_el, element_name) != Interactivity::NonInteractive {
+            return;
+        }
+
+        let mut has_spread = false;
+        let mut attributes = FxHashSet::default();
+        for attr in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attribute) = attr else {
+                has_spread = true;
+                continue;
+            };
+            if let JSXAttributeName::Identifier(ident) = &attribute.name {
+                attributes.insert(ident.name.as_str());
+            }
+        }
+        if has_spread {
+            return;
+        }
+
+        if !INTERACTION_HANDLERS.iter().any(|h| attributes.contains(h)) {
+            return;
+        }
+
+        // A declared role makes the element's purpose explicit, so it is exempt.
+        if has_explicit_role(jsx_el) {
+            return;
+        }
+
+        ctx.diagnostic(NoStaticElementInteractionsDiagnostic(name.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    fn settings() -> serde_json::Value {
+        serde_json::json!({
+            "jsx-a11y": {
+                "components": {
+                    "MyComponent": "div",
+                }
+            }
+        })
+    }
+
+    let pass = vec![
+        ("<div onClick={() => void 0} role='button' />", None, None, None),
+        ("<button onClick={() => void 0} />", None, None, None),
+        ("<div />", None, None, None),
+        ("<div onClick={() => void 0} {...props} />", None, None, None),
+        ("<Foo onClick={() => void 0} />", None, None, None),
+        ("<MyComponent onClick={() => void 0} role='button' />", None, Some(settings()), None),
+    ];
+
+    let fail = vec![
+        ("<div onClick={() => void 0} />", None, None, None),
+        ("<span onKeyDown={() => void 0} />", None, None, None),
+        ("<p onMouseDown={() => void 0} />", None, None, None),
+        ("<MyComponent onClick={() => void 0} />", None, Some(settings()), None),
+    ];
+
+    Tester::new_with_settings(NoStaticElementInteractions::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,14 +1,16 @@
-use crate::{context::LintContext, rule::Rule, utils::has_jsx_prop_lowercase, AstNode};
-use oxc_ast::{
-    ast::{JSXAttributeItem, JSXAttributeValue},
-    AstKind,
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{eval_jsx_attribute_value, has_jsx_prop_lowercase},
+    AstNode,
 };
+use oxc_ast::{ast::JSXAttributeItem, AstKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use phf::{phf_map, phf_set};
 
 #[derive(Debug, Error, Diagnostic)]
@@ -58,22 +60,43 @@ static ROLE_TO_REQUIRED_ARIA_PROPS: phf::Map<&'static str, phf::Set<&'static str
     "option" => phf_set!{"aria-selected"},
 };
 
+/// A placeholder value for a required aria prop, used by the fixer. The values
+/// for boolean, numeric and token props are well-typed for the `aria-proptypes`
+/// rule; id-valued props such as `aria-controls` get an empty string, which
+/// that rule still reports.
+fn default_aria_value(prop: &str) -> &'static str {
+    match prop {
+        "aria-level" => "1",
+        "aria-valuemax" => "100",
+        "aria-valuemin" | "aria-valuenow" => "0",
+        "aria-orientation" => "horizontal",
+        "aria-checked" | "aria-selected" | "aria-expanded" => "false",
+        _ => "",
+    }
+}
+
 impl Rule for RoleHasRequiredAriaProps {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(role_prop) = has_jsx_prop_lowercase(jsx_el, "role") else { return };
             let JSXAttributeItem::Attribute(attr) = role_prop else { return };
-            let Some(JSXAttributeValue::StringLiteral(role_values)) = &attr.value else { return };
-            let roles = role_values.value.split_whitespace();
+            let role_value = eval_jsx_attribute_value(role_prop);
+            let Some(role_value) = role_value.as_str() else { return };
+            let roles = role_value.split_whitespace();
             for role in roles {
                 if let Some(props) = ROLE_TO_REQUIRED_ARIA_PROPS.get(role) {
                     for prop in props {
                         if has_jsx_prop_lowercase(jsx_el, prop).is_none() {
-                            ctx.diagnostic(RoleHasRequiredAriaPropsDiagnostic {
-                                span: attr.span,
-                                role: role.into(),
-                                props: (*prop).into(),
-                            });
+                            let name_span = jsx_el.name.span();
+                            let insertion = format!(r#" {prop}="{}""#, default_aria_value(prop));
+                            ctx.diagnostic_with_fix(
+                                RoleHasRequiredAriaPropsDiagnostic {
+                                    span: attr.span,
+                                    role: role.into(),
+                                    props: (*prop).into(),
+                                },
+                                |fixer| fixer.insert_after(name_span, insertion),
+                            );
                         }
                     }
                 }
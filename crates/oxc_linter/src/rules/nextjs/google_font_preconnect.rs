@@ -1,4 +1,7 @@
-use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXElementName},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +12,7 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_string_literal_prop_value, has_jsx_prop_lowercase},
+    utils::{eval_jsx_attribute_value, has_jsx_prop_lowercase},
     AstNode,
 };
 
@@ -53,16 +56,26 @@ impl Rule for GoogleFontPreconnect {
         let Some(href_prop) = has_jsx_prop_lowercase(jsx_opening_element, "href") else {
             return;
         };
-        let Some(href_prop_value) = get_string_literal_prop_value(href_prop) else { return };
+        let href_prop_value = eval_jsx_attribute_value(href_prop);
+        let Some(href_prop_value) = href_prop_value.as_str() else { return };
 
-        let preconnect_missing =
-            has_jsx_prop_lowercase(jsx_opening_element, "rel").map_or(true, |rel_prop| {
-                let rel_prop_value = get_string_literal_prop_value(rel_prop);
-                rel_prop_value != Some("preconnect")
-            });
+        let rel_prop = has_jsx_prop_lowercase(jsx_opening_element, "rel");
+        let preconnect_missing = rel_prop
+            .map_or(true, |rel_prop| eval_jsx_attribute_value(rel_prop).as_str() != Some("preconnect"));
 
         if href_prop_value.starts_with("https://fonts.gstatic.com") && preconnect_missing {
-            ctx.diagnostic(GoogleFontPreconnectDiagnostic(jsx_opening_element_name.span));
+            let name_span = jsx_opening_element_name.span;
+            let rel_span = match rel_prop {
+                Some(JSXAttributeItem::Attribute(attr)) => Some(attr.span),
+                _ => None,
+            };
+            ctx.diagnostic_with_fix(
+                GoogleFontPreconnectDiagnostic(name_span),
+                |fixer| match rel_span {
+                    Some(span) => fixer.replace(span, r#"rel="preconnect""#),
+                    None => fixer.insert_after(name_span, r#" rel="preconnect""#),
+                },
+            );
         }
     }
 }
@@ -8,9 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{eval_jsx_attribute_value, StaticValue},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-next(no-sync-scripts): Prevent synchronous scripts.")]
@@ -46,31 +51,37 @@ impl Rule for NoSyncScripts {
             return;
         }
 
-        let attributes_hs =
-            jsx_opening_element
-                .attributes
-                .iter()
-                .filter_map(|v| {
-                    if let JSXAttributeItem::Attribute(v) = v {
-                        Some(&v.name)
-                    } else {
-                        None
-                    }
-                })
-                .filter_map(|v| {
-                    if let JSXAttributeName::Identifier(v) = v {
-                        Some(v.name.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect::<FxHashSet<_>>();
+        let named_attributes = jsx_opening_element
+            .attributes
+            .iter()
+            .filter_map(|v| {
+                let JSXAttributeItem::Attribute(attr) = v else { return None };
+                let JSXAttributeName::Identifier(name) = &attr.name else { return None };
+                Some((name.name.clone(), v))
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        // A script is only loaded synchronously when it has a `src` and neither
+        // `async` nor `defer` is present with a value other than a static
+        // `false`, `null` or `undefined`. An explicit `async={false}` therefore
+        // still counts as synchronous, while a value that cannot be resolved
+        // statically counts as set.
+        let is_deferred = |attr_name: &str| {
+            named_attributes.get(attr_name).is_some_and(|attr| {
+                !matches!(
+                    eval_jsx_attribute_value(attr),
+                    StaticValue::Boolean(false)
+                        | StaticValue::Null
+                        | StaticValue::Undefined
+                )
+            })
+        };
 
-        if attributes_hs.contains("src")
-            && !attributes_hs.contains("async")
-            && !attributes_hs.contains("defer")
-        {
-            ctx.diagnostic(NoSyncScriptsDiagnostic(jsx_opening_element_name.span));
+        if named_attributes.contains_key("src") && !is_deferred("async") && !is_deferred("defer") {
+            let name_span = jsx_opening_element_name.span;
+            ctx.diagnostic_with_fix(NoSyncScriptsDiagnostic(name_span), |fixer| {
+                fixer.insert_after(name_span, " async")
+            });
         }
     }
 }
@@ -0,0 +1,133 @@
+use oxc_ast::ast::JSXOpeningElement;
+use phf::{phf_map, phf_set, Set};
+
+use super::{eval_jsx_attribute_value, has_jsx_prop_lowercase};
+
+/// Intrinsic elements that are interactive on their own.
+static INTERACTIVE_ELEMENTS: Set<&'static str> = phf_set! {
+    "a", "audio", "button", "canvas", "details", "embed", "input", "keygen",
+    "label", "menuitem", "option", "select", "summary", "textarea", "tr", "video",
+};
+
+/// Intrinsic elements that never carry interaction semantics.
+static NON_INTERACTIVE_ELEMENTS: Set<&'static str> = phf_set! {
+    "article", "blockquote", "br", "caption", "dd", "dfn", "dir", "div", "dl",
+    "dt", "fieldset", "figcaption", "figure", "footer", "form", "frame", "h1",
+    "h2", "h3", "h4", "h5", "h6", "header", "hr", "img", "li", "main", "mark",
+    "nav", "ol", "p", "pre", "section", "span", "table", "tbody", "td", "tfoot",
+    "thead", "ul",
+};
+
+static INTERACTIVE_ROLES: Set<&'static str> = phf_set! {
+    "button", "checkbox", "columnheader", "combobox", "grid", "gridcell",
+    "link", "listbox", "menu", "menubar", "menuitem", "menuitemcheckbox",
+    "menuitemradio", "option", "progressbar", "radio", "radiogroup", "row",
+    "rowheader", "scrollbar", "searchbox", "slider", "spinbutton", "switch",
+    "tab", "tablist", "textbox", "treeitem",
+};
+
+static NON_INTERACTIVE_ROLES: Set<&'static str> = phf_set! {
+    "article", "banner", "complementary", "contentinfo", "definition",
+    "directory", "document", "feed", "figure", "group", "heading", "img",
+    "list", "listitem", "main", "marquee", "math", "navigation", "note",
+    "region", "rowgroup", "separator", "status", "tabpanel", "term", "timer",
+    "toolbar", "tooltip",
+};
+
+/// The ARIA role an intrinsic element carries implicitly.
+static IMPLICIT_ROLES: phf::Map<&'static str, &'static str> = phf_map! {
+    "a" => "link",
+    "area" => "link",
+    "article" => "article",
+    "button" => "button",
+    "checkbox" => "checkbox",
+    "h1" => "heading",
+    "h2" => "heading",
+    "h3" => "heading",
+    "h4" => "heading",
+    "h5" => "heading",
+    "h6" => "heading",
+    "img" => "img",
+    "input" => "textbox",
+    "li" => "listitem",
+    "link" => "link",
+    "menuitem" => "menuitem",
+    "nav" => "navigation",
+    "ol" => "list",
+    "option" => "option",
+    "select" => "listbox",
+    "textarea" => "textbox",
+    "ul" => "list",
+};
+
+/// How an element's effective role classifies for the interaction rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interactivity {
+    Interactive,
+    NonInteractive,
+    /// Neither table recognises the effective role (custom or unknown).
+    Unknown,
+}
+
+/// Whether the element carries a statically-known, non-empty explicit `role`.
+pub fn has_explicit_role(jsx_el: &JSXOpeningElement) -> bool {
+    has_jsx_prop_lowercase(jsx_el, "role")
+        .and_then(|role_prop| {
+            eval_jsx_attribute_value(role_prop)
+                .as_str()
+                .map(|role| role.split_whitespace().next().is_some())
+        })
+        .unwrap_or(false)
+}
+
+/// Classify an element by its effective role: the first token of an explicit,
+/// statically-known `role` attribute when present, otherwise the implicit role
+/// of the tag, otherwise the tag's own interactivity.
+pub fn element_interactivity(jsx_el: &JSXOpeningElement, element_name: &str) -> Interactivity {
+    if let Some(role_prop) = has_jsx_prop_lowercase(jsx_el, "role") {
+        if let Some(role) = eval_jsx_attribute_value(role_prop).as_str() {
+            if let Some(token) = role.split_whitespace().next() {
+                return classify_role(token);
+            }
+        }
+    }
+    if let Some(implicit) = IMPLICIT_ROLES.get(element_name) {
+        let by_role = classify_role(implicit);
+        if by_role != Interactivity::Unknown {
+            return by_role;
+        }
+    }
+    if is_interactive_element(element_name) {
+        Interactivity::Interactive
+    } else if is_non_interactive_element(element_name) {
+        Interactivity::NonInteractive
+    } else {
+        Interactivity::Unknown
+    }
+}
+
+fn classify_role(role: &str) -> Interactivity {
+    if is_interactive_role(role) {
+        Interactivity::Interactive
+    } else if is_non_interactive_role(role) {
+        Interactivity::NonInteractive
+    } else {
+        Interactivity::Unknown
+    }
+}
+
+pub fn is_interactive_element(element_name: &str) -> bool {
+    INTERACTIVE_ELEMENTS.contains(element_name)
+}
+
+pub fn is_non_interactive_element(element_name: &str) -> bool {
+    NON_INTERACTIVE_ELEMENTS.contains(element_name)
+}
+
+pub fn is_interactive_role(role: &str) -> bool {
+    INTERACTIVE_ROLES.contains(role)
+}
+
+pub fn is_non_interactive_role(role: &str) -> bool {
+    NON_INTERACTIVE_ROLES.contains(role)
+}
@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use oxc_ast::ast::{
+    Expression, JSXAttributeItem, JSXAttributeValue, JSXExpression, JSXExpressionContainer,
+};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+
+/// The statically-known value of a JSX attribute or expression.
+///
+/// a11y rules frequently need to reason about the *value* of an attribute
+/// (`role`, `rel`, `aria-checked`, …) rather than merely its presence. Most of
+/// the time that value is a plain string literal, but authors routinely write
+/// `role={cond ? 'button' : 'link'}` or `rel={"pre" + "connect"}`. This enum
+/// captures the subset of values we can fold at lint time; anything we cannot
+/// resolve collapses to [`StaticValue::Unknown`] so callers bail out instead of
+/// reporting a false positive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticValue<'a> {
+    String(Cow<'a, str>),
+    Boolean(bool),
+    Number(f64),
+    Null,
+    Undefined,
+    Unknown,
+}
+
+impl<'a> StaticValue<'a> {
+    /// Returns the value as a string slice when it is a known string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            StaticValue::String(s) => Some(s.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// JavaScript truthiness, or `None` when the value is not statically known.
+    fn is_truthy(&self) -> Option<bool> {
+        match self {
+            StaticValue::String(s) => Some(!s.is_empty()),
+            StaticValue::Boolean(b) => Some(*b),
+            StaticValue::Number(n) => Some(*n != 0.0 && !n.is_nan()),
+            StaticValue::Null | StaticValue::Undefined => Some(false),
+            StaticValue::Unknown => None,
+        }
+    }
+
+    /// The string coercion (`String(value)`) when every part is known, used to
+    /// fold template literals. Returns `None` for [`StaticValue::Unknown`].
+    fn to_coerced_string(&self) -> Option<Cow<'a, str>> {
+        match self {
+            StaticValue::String(s) => Some(s.clone()),
+            StaticValue::Boolean(b) => Some(Cow::Borrowed(if *b { "true" } else { "false" })),
+            StaticValue::Number(n) => Some(Cow::Owned(n.to_string())),
+            StaticValue::Null => Some(Cow::Borrowed("null")),
+            StaticValue::Undefined => Some(Cow::Borrowed("undefined")),
+            StaticValue::Unknown => None,
+        }
+    }
+}
+
+/// Evaluate a JSX attribute to its statically-known [`StaticValue`].
+///
+/// A valueless attribute (`aria-busy`) evaluates to `true`, matching the HTML
+/// boolean-attribute semantics; an expression container defers to
+/// [`eval_expression`].
+pub fn eval_jsx_attribute_value<'a>(attr: &JSXAttributeItem<'a>) -> StaticValue<'a> {
+    let JSXAttributeItem::Attribute(attr) = attr else { return StaticValue::Unknown };
+    match &attr.value {
+        None => StaticValue::Boolean(true),
+        Some(JSXAttributeValue::StringLiteral(lit)) => {
+            StaticValue::String(Cow::Borrowed(lit.value.as_str()))
+        }
+        Some(JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
+            expression: JSXExpression::Expression(expr),
+            ..
+        })) => eval_expression(expr),
+        _ => StaticValue::Unknown,
+    }
+}
+
+/// Fold an [`Expression`] into a [`StaticValue`].
+///
+/// Handles string/number/boolean/null literals, `undefined`, parenthesized
+/// expressions, template literals whose interpolations are themselves static,
+/// the short-circuiting logical operators `||`, `??` and `&&` (returning
+/// the branch that is statically selected), and `+` over statically-known
+/// operands. Everything else is `Unknown`.
+pub fn eval_expression<'a>(expr: &Expression<'a>) -> StaticValue<'a> {
+    match expr {
+        Expression::StringLiteral(lit) => StaticValue::String(Cow::Borrowed(lit.value.as_str())),
+        Expression::NumericLiteral(lit) => StaticValue::Number(lit.value),
+        Expression::BooleanLiteral(lit) => StaticValue::Boolean(lit.value),
+        Expression::NullLiteral(_) => StaticValue::Null,
+        Expression::Identifier(ident) if ident.name == "undefined" => StaticValue::Undefined,
+        Expression::ParenthesizedExpression(paren) => eval_expression(&paren.expression),
+        Expression::TemplateLiteral(tpl) => eval_template_literal(tpl),
+        Expression::LogicalExpression(logical) => {
+            let left = eval_expression(&logical.left);
+            match logical.operator {
+                LogicalOperator::Or => match left.is_truthy() {
+                    Some(true) => left,
+                    Some(false) => eval_expression(&logical.right),
+                    None => StaticValue::Unknown,
+                },
+                LogicalOperator::And => match left.is_truthy() {
+                    Some(true) => eval_expression(&logical.right),
+                    Some(false) => left,
+                    None => StaticValue::Unknown,
+                },
+                LogicalOperator::Coalesce => match left {
+                    StaticValue::Null | StaticValue::Undefined => eval_expression(&logical.right),
+                    StaticValue::Unknown => StaticValue::Unknown,
+                    known => known,
+                },
+            }
+        }
+        Expression::BinaryExpression(bin) if bin.operator == BinaryOperator::Addition => {
+            eval_addition(&bin.left, &bin.right)
+        }
+        _ => StaticValue::Unknown,
+    }
+}
+
+/// Fold a `+` over statically-known operands: numeric addition when neither
+/// side is a string, string concatenation otherwise (matching JS coercion), and
+/// `Unknown` as soon as either side is unresolved.
+fn eval_addition<'a>(left: &Expression<'a>, right: &Expression<'a>) -> StaticValue<'a> {
+    let left = eval_expression(left);
+    let right = eval_expression(right);
+    // Without a string operand, JS adds the `ToNumber` of both sides.
+    let to_number = |value: &StaticValue| match value {
+        StaticValue::Number(n) => Some(*n),
+        StaticValue::Boolean(b) => Some(f64::from(u8::from(*b))),
+        StaticValue::Null => Some(0.0),
+        StaticValue::Undefined => Some(f64::NAN),
+        StaticValue::String(_) | StaticValue::Unknown => None,
+    };
+    if let (Some(l), Some(r)) = (to_number(&left), to_number(&right)) {
+        return StaticValue::Number(l + r);
+    }
+    match (left.to_coerced_string(), right.to_coerced_string()) {
+        (Some(l), Some(r)) => StaticValue::String(Cow::Owned(format!("{l}{r}"))),
+        _ => StaticValue::Unknown,
+    }
+}
+
+fn eval_template_literal<'a>(tpl: &oxc_ast::ast::TemplateLiteral<'a>) -> StaticValue<'a> {
+    // A template with no interpolations is a single cooked quasi.
+    if tpl.expressions.is_empty() {
+        return match tpl.quasis.first().and_then(|q| q.value.cooked.as_ref()) {
+            Some(cooked) => StaticValue::String(Cow::Owned(cooked.to_string())),
+            None => StaticValue::Unknown,
+        };
+    }
+
+    let mut out = String::new();
+    let mut quasis = tpl.quasis.iter();
+    for expr in &tpl.expressions {
+        let Some(quasi) = quasis.next() else { return StaticValue::Unknown };
+        let Some(cooked) = quasi.value.cooked.as_ref() else { return StaticValue::Unknown };
+        out.push_str(cooked.as_str());
+        let Some(part) = eval_expression(expr).to_coerced_string() else {
+            return StaticValue::Unknown;
+        };
+        out.push_str(&part);
+    }
+    if let Some(last) = quasis.next() {
+        let Some(cooked) = last.value.cooked.as_ref() else { return StaticValue::Unknown };
+        out.push_str(cooked.as_str());
+    }
+
+    StaticValue::String(Cow::Owned(out))
+}